@@ -56,22 +56,171 @@ pub struct Iter<'a>(pub(crate) IterImpl<&'a ByteSet>);
 #[derive(Debug, Clone)]
 pub struct IntoIter(pub(crate) IterImpl<ByteSet>);
 
+/// An iterator over the elements of a [`ByteSet`](crate::ByteSet) that fall within a given
+/// bound, in increasing order.
+///
+/// This `struct` is created by the [`range`](crate::ByteSet::range) method.
+#[derive(Debug, Clone)]
+pub struct Range<'a>(pub(crate) IterImpl<&'a ByteSet>);
+
 wrapped! {
     impl Iterator<Item = u8> for Iter<'_>;
     impl Iterator<Item = u8> for IntoIter;
+    impl Iterator<Item = u8> for Range<'_>;
     impl DoubleEndedIterator for Iter<'_>;
     impl DoubleEndedIterator for IntoIter;
+    impl DoubleEndedIterator for Range<'_>;
     impl FusedIterator for Iter<'_>;
     impl FusedIterator for IntoIter;
+    impl FusedIterator for Range<'_>;
+}
+
+/// An iterator over the union of two [`ByteSet`](crate::ByteSet)s, in increasing order.
+///
+/// This `struct` is created by the [`union_iter`](crate::ByteSet::union_iter) method.
+#[derive(Debug, Clone)]
+pub struct Union(pub(crate) IterImpl<ByteSet>);
+
+/// An iterator over the intersection of two [`ByteSet`](crate::ByteSet)s, in increasing order.
+///
+/// This `struct` is created by the [`intersection_iter`](crate::ByteSet::intersection_iter)
+/// method.
+#[derive(Debug, Clone)]
+pub struct Intersection(pub(crate) IterImpl<ByteSet>);
+
+/// An iterator over the difference of two [`ByteSet`](crate::ByteSet)s, in increasing order.
+///
+/// This `struct` is created by the [`difference_iter`](crate::ByteSet::difference_iter) method.
+#[derive(Debug, Clone)]
+pub struct Difference(pub(crate) IterImpl<ByteSet>);
+
+/// An iterator over the symmetric difference of two [`ByteSet`](crate::ByteSet)s, in increasing
+/// order.
+///
+/// This `struct` is created by the
+/// [`symmetric_difference_iter`](crate::ByteSet::symmetric_difference_iter) method.
+#[derive(Debug, Clone)]
+pub struct SymmetricDifference(pub(crate) IterImpl<ByteSet>);
+
+wrapped! {
+    impl Iterator<Item = u8> for Union;
+    impl Iterator<Item = u8> for Intersection;
+    impl Iterator<Item = u8> for Difference;
+    impl Iterator<Item = u8> for SymmetricDifference;
+    impl DoubleEndedIterator for Union;
+    impl DoubleEndedIterator for Intersection;
+    impl DoubleEndedIterator for Difference;
+    impl DoubleEndedIterator for SymmetricDifference;
+    impl FusedIterator for Union;
+    impl FusedIterator for Intersection;
+    impl FusedIterator for Difference;
+    impl FusedIterator for SymmetricDifference;
+}
+
+/// A draining iterator over the elements of a [`ByteSet`](crate::ByteSet), in increasing order.
+///
+/// This `struct` is created by the [`drain`](crate::ByteSet::drain) method. Like `Vec::drain`,
+/// the set is left empty once this iterator is dropped, even if it was dropped before being
+/// fully consumed.
+#[derive(Debug)]
+pub struct Drain<'a> {
+    set: &'a mut ByteSet,
+    range: RangeInclusive<u8>,
+}
+
+impl<'a> Drain<'a> {
+    pub(crate) fn new(set: &'a mut ByteSet) -> Self {
+        let range = set.span();
+        Self { set, range }
+    }
+}
+
+impl Iterator for Drain<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(val) = self.range.next() {
+            if self.set.remove(val) {
+                return Some(val);
+            }
+        }
+        None
+    }
+
+    fn min(mut self) -> Option<Self::Item> {
+        self.next()
+    }
+    fn max(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
 }
 
+impl DoubleEndedIterator for Drain<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while let Some(val) = self.range.next_back() {
+            if self.set.remove(val) {
+                return Some(val);
+            }
+        }
+        None
+    }
+}
+
+impl FusedIterator for Drain<'_> {}
+
+impl Drop for Drain<'_> {
+    fn drop(&mut self) {
+        self.set.clear();
+    }
+}
+
+/// An iterator that removes and yields the elements of a [`ByteSet`](crate::ByteSet) matching a
+/// predicate, in increasing order.
+///
+/// This `struct` is created by the [`extract_if`](crate::ByteSet::extract_if) method. Elements
+/// for which the predicate returns `true` are removed from the set and yielded; elements for
+/// which it returns `false` are left in place. Elements not yet visited when this iterator is
+/// dropped remain in the set unchanged.
+pub struct ExtractIf<'a, F> {
+    set: &'a mut ByteSet,
+    range: RangeInclusive<u8>,
+    pred: F,
+}
+
+impl<'a, F: FnMut(u8) -> bool> ExtractIf<'a, F> {
+    pub(crate) fn new(set: &'a mut ByteSet, pred: F) -> Self {
+        let range = set.span();
+        Self { set, range, pred }
+    }
+}
+
+impl<F: FnMut(u8) -> bool> Iterator for ExtractIf<'_, F> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for val in self.range.by_ref() {
+            if self.set.contains(val) && (self.pred)(val) {
+                self.set.remove(val);
+                return Some(val);
+            }
+        }
+        None
+    }
+}
+
+impl<F: FnMut(u8) -> bool> FusedIterator for ExtractIf<'_, F> {}
+
 impl<T: Borrow<ByteSet>> IterImpl<T> {
     pub(crate) fn new(set: T) -> Self {
         Self {
-            range: set.borrow().range(),
+            range: set.borrow().span(),
             set,
         }
     }
+
+    pub(crate) fn with_range(set: T, range: RangeInclusive<u8>) -> Self {
+        Self { range, set }
+    }
 }
 
 impl<T: Borrow<ByteSet>> Iterator for IterImpl<T> {
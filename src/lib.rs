@@ -1,21 +1,31 @@
-#![no_std]
+#![cfg_attr(not(test), no_std)]
 
 use core::{
     borrow::Borrow,
     iter::{Extend, FromIterator},
-    ops::{Index, RangeInclusive},
+    ops::{Bound, Index, RangeBounds, RangeInclusive},
 };
 use ethnum::u256;
 
 pub mod iter;
 mod ops;
+#[cfg(feature = "serde")]
+mod serde_impl;
 
 /// A bitfield-based set of 8-bit values (think `[bool; 256]`),
 /// exposing an interface similar to `HashSet<u8>`.
 ///
 /// `ByteSet`'s specialized nature allows it to be implemented as a wrapper around 32 bytes of
 /// stack space, with no heap allocation, resizing, or indirection.
+///
+/// With the `serde` feature enabled, `ByteSet` implements `Serialize`/`Deserialize`: human-readable
+/// formats see a sequence of the contained `u8`s, while binary formats see the raw 32-byte bitmask.
+///
+/// That 32-byte bitmask is itself a stable, documented layout: byte `n / 8`, bit `n % 8` (little-
+/// endian) is set if and only if `n` is a member of the set. [`to_le_bytes`](Self::to_le_bytes)
+/// and [`from_le_bytes`](Self::from_le_bytes) convert to and from it directly.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
 pub struct ByteSet(u256);
 
 impl ByteSet {
@@ -23,7 +33,7 @@ impl ByteSet {
         u256::ONE << val.borrow()
     }
 
-    fn range(&self) -> RangeInclusive<u8> {
+    fn span(&self) -> RangeInclusive<u8> {
         match (self.min(), self.max()) {
             (Some(min), Some(max)) => min..=max,
             // whatever, just an empty range
@@ -41,6 +51,31 @@ impl ByteSet {
         Self(u256::MAX)
     }
 
+    /// Creates a `ByteSet` from its raw 32-byte bitmask representation, as produced by
+    /// [`to_le_bytes`](Self::to_le_bytes): bit `n` of the bitmask (byte `n / 8`, bit `n % 8`,
+    /// little-endian) set means `n` is a member of the set.
+    pub fn from_le_bytes(bytes: [u8; 32]) -> Self {
+        Self(u256::from_le_bytes(bytes))
+    }
+
+    /// Returns the raw 32-byte bitmask representation of the set: bit `n` of the bitmask (byte
+    /// `n / 8`, bit `n % 8`, little-endian) is set if and only if `n` is a member of the set.
+    pub fn to_le_bytes(self) -> [u8; 32] {
+        self.0.to_le_bytes()
+    }
+
+    /// Returns a reference to the set's raw 32-byte bitmask representation, with the same layout
+    /// as [`to_le_bytes`](Self::to_le_bytes).
+    ///
+    /// Only available on little-endian targets, where `u256`'s in-memory representation already
+    /// matches `to_le_bytes`; elsewhere, use `to_le_bytes` directly.
+    #[cfg(target_endian = "little")]
+    pub fn as_byte_slice(&self) -> &[u8; 32] {
+        // SAFETY: `ByteSet` is `repr(transparent)` over `u256`, which on little-endian targets
+        // has the same bit pattern as the `[u8; 32]` produced by `to_le_bytes`.
+        unsafe { &*(self as *const Self as *const [u8; 32]) }
+    }
+
     /// Creates a `ByteSet` based on a predicate function.
     pub fn from_predicate<F: FnMut(u8) -> bool>(mut f: F) -> Self {
         (0u8..=255).filter(|n| f(*n)).collect()
@@ -51,6 +86,30 @@ impl ByteSet {
         iter::Iter(iter::IterImpl::new(self))
     }
 
+    /// An iterator visiting the elements in `bounds`, in increasing order.
+    ///
+    /// Like `BTreeSet::range`, this yields only the contained values whose element falls within
+    /// `bounds`; out-of-range or inverted bounds (e.g. `range(..0)`, `range(256..)` via an
+    /// `Excluded` bound on `u8::MAX`) simply produce an empty iterator.
+    pub fn range<R: RangeBounds<u8>>(&self, bounds: R) -> iter::Range<'_> {
+        let start = match bounds.start_bound() {
+            Bound::Unbounded => Some(0u8),
+            Bound::Included(&n) => Some(n),
+            Bound::Excluded(&n) => n.checked_add(1),
+        };
+        let end = match bounds.end_bound() {
+            Bound::Unbounded => Some(255u8),
+            Bound::Included(&n) => Some(n),
+            Bound::Excluded(&n) => n.checked_sub(1),
+        };
+        let range = match (start, end) {
+            (Some(start), Some(end)) if start <= end => start..=end,
+            // whatever, just an empty range
+            _ => 1..=0,
+        };
+        iter::Range(iter::IterImpl::with_range(self, range))
+    }
+
     /// An iterator visiting each possible element in increasing order,
     /// accompanied by whether the set contains it.
     /// The iterator element type is `(u8, bool)`.
@@ -104,30 +163,65 @@ impl ByteSet {
         self.0 = u256::ZERO;
     }
 
+    /// Clears the set, returning all its elements as an iterator in increasing order.
+    ///
+    /// Like `Vec::drain`, if the returned iterator is dropped before being fully consumed, it
+    /// drops the remaining elements rather than leaving them in the set.
+    pub fn drain(&mut self) -> iter::Drain<'_> {
+        iter::Drain::new(self)
+    }
+
     /// Returns a new `ByteSet` representing the difference,
     /// i.e. the values that are in `self` but not in `other`.
     pub fn difference(&self, other: &Self) -> Self {
         self - other
     }
 
+    /// Returns an iterator over the difference, i.e. the values that are in `self` but not in
+    /// `other`, in increasing order.
+    pub fn difference_iter(&self, other: &Self) -> iter::Difference {
+        // Built directly from the bitmasks (AND-NOT) rather than `self.difference(other)`:
+        // the latter goes through `Sub`, which is integer subtraction on the underlying `u256`
+        // and panics on overflow when `other` has bits set above `self`'s highest set bit.
+        iter::Difference(iter::IterImpl::new(Self(self.0 & !other.0)))
+    }
+
     /// Returns a new `ByteSet` representing the symmetric difference,
     /// i.e. the values that are in `self` or in `other` but not in both.
     pub fn symmetric_difference(&self, other: &Self) -> Self {
         self ^ other
     }
 
+    /// Returns an iterator over the symmetric difference, i.e. the values that are in `self` or
+    /// in `other` but not in both, in increasing order.
+    pub fn symmetric_difference_iter(&self, other: &Self) -> iter::SymmetricDifference {
+        iter::SymmetricDifference(iter::IterImpl::new(self.symmetric_difference(other)))
+    }
+
     /// Returns a new `ByteSet` representing the intersection,
     /// i.e. the values that are both in `self` and `other.`
     pub fn intersection(&self, other: &Self) -> Self {
         self & other
     }
 
+    /// Returns an iterator over the intersection, i.e. the values that are both in `self` and
+    /// `other`, in increasing order.
+    pub fn intersection_iter(&self, other: &Self) -> iter::Intersection {
+        iter::Intersection(iter::IterImpl::new(self.intersection(other)))
+    }
+
     /// Returns a new `ByteSet` representing the union,
     /// i.e. all the values in `self` or `other`.
     pub fn union(&self, other: &Self) -> Self {
         self | other
     }
 
+    /// Returns an iterator over the union, i.e. all the values in `self` or `other`, in
+    /// increasing order.
+    pub fn union_iter(&self, other: &Self) -> iter::Union {
+        iter::Union(iter::IterImpl::new(self.union(other)))
+    }
+
     /// Returns `true` if the set contains a value.
     /// The value may be passed as a `u8` or as any borrowed form of `u8`.
     pub fn contains<T: Borrow<u8>>(&self, val: T) -> bool {
@@ -198,6 +292,32 @@ impl ByteSet {
             }
         }
     }
+
+    /// Splits the set in two at the given value.
+    ///
+    /// Returns a new `ByteSet` containing all elements `>= at`; `self` is left with only the
+    /// elements `< at`.
+    pub fn split_off(&mut self, at: u8) -> Self {
+        let high = !Self::mask(at).wrapping_sub(u256::ONE);
+        let tail = Self(self.0 & high);
+        self.0 &= !high;
+        tail
+    }
+
+    /// Moves all elements from `other` into `self`, leaving `other` empty.
+    pub fn append(&mut self, other: &mut Self) {
+        self.0 |= other.0;
+        other.0 = u256::ZERO;
+    }
+
+    /// Removes and returns, as an iterator, the elements for which `pred` returns `true`.
+    ///
+    /// Like [`retain`](Self::retain) in reverse: elements for which `pred` returns `false` are
+    /// left in the set. If the returned iterator is dropped before being fully consumed, the
+    /// not-yet-visited elements are left in the set unchanged.
+    pub fn extract_if<F: FnMut(u8) -> bool>(&mut self, pred: F) -> iter::ExtractIf<'_, F> {
+        iter::ExtractIf::new(self, pred)
+    }
 }
 
 impl Default for ByteSet {
@@ -266,3 +386,103 @@ impl From<ByteSet> for [bool; 256] {
         Self::from(&set)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn le_bytes_round_trip() {
+        for set in [
+            ByteSet::new(),
+            ByteSet::full(),
+            ByteSet::from_iter([0u8]),
+            ByteSet::from_iter([255u8]),
+            ByteSet::from_iter([0u8, 1, 127, 128, 255]),
+        ] {
+            assert_eq!(ByteSet::from_le_bytes(set.to_le_bytes()), set);
+        }
+    }
+
+    #[test]
+    fn le_bytes_bit_layout() {
+        // bit n set <=> byte n/8, bit n%8 (little-endian) set
+        let set = ByteSet::from_iter([0u8, 9, 255]);
+        let bytes = set.to_le_bytes();
+        assert_eq!(bytes[0], 0b0000_0001);
+        assert_eq!(bytes[1], 0b0000_0010);
+        assert_eq!(bytes[31], 0b1000_0000);
+        for (i, &byte) in bytes.iter().enumerate() {
+            if i != 0 && i != 1 && i != 31 {
+                assert_eq!(byte, 0, "unexpected bits set in byte {i}");
+            }
+        }
+    }
+
+    #[cfg(target_endian = "little")]
+    #[test]
+    fn as_byte_slice_matches_to_le_bytes() {
+        let set = ByteSet::from_iter([3u8, 100, 200]);
+        assert_eq!(set.as_byte_slice(), &set.to_le_bytes());
+    }
+
+    #[test]
+    fn range_excludes_out_of_bounds() {
+        let set = ByteSet::full();
+        assert_eq!(set.range(..0).next(), None);
+    }
+
+    #[test]
+    fn range_excluded_max_start_is_empty() {
+        // Excluded(255) as a start bound is the u8-space equivalent of `256..`: it must not
+        // panic on the `checked_add` overflow, and must simply yield nothing.
+        let set = ByteSet::full();
+        assert_eq!(
+            set.range((core::ops::Bound::Excluded(255u8), core::ops::Bound::Unbounded))
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn range_is_inclusive_bounded_subset() {
+        let set: ByteSet = (0u8..=20).collect();
+        let got: std::vec::Vec<u8> = set.range(5..=10).collect();
+        assert_eq!(got, (5u8..=10).collect::<std::vec::Vec<u8>>());
+    }
+
+    #[test]
+    fn range_empty_when_start_after_end() {
+        let set = ByteSet::full();
+        assert_eq!(set.range(10..=5).count(), 0);
+    }
+
+    #[test]
+    fn difference_iter_does_not_overflow() {
+        // `other` has bits set above `self`'s highest set bit, which panicked when
+        // `difference_iter` was built on top of `self.difference(other)` (integer `Sub`).
+        let a: ByteSet = (0u8..=5).collect();
+        let b: ByteSet = (3u8..=8).collect();
+        let got: std::vec::Vec<u8> = a.difference_iter(&b).collect();
+        assert_eq!(got, std::vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn set_op_iters_match_owned_variants() {
+        let a: ByteSet = [1u8, 2, 3].into_iter().collect();
+        let b: ByteSet = [3u8, 4, 5].into_iter().collect();
+
+        assert_eq!(
+            a.union_iter(&b).collect::<std::vec::Vec<u8>>(),
+            a.union(&b).iter().collect::<std::vec::Vec<u8>>()
+        );
+        assert_eq!(
+            a.intersection_iter(&b).collect::<std::vec::Vec<u8>>(),
+            a.intersection(&b).iter().collect::<std::vec::Vec<u8>>()
+        );
+        assert_eq!(
+            a.symmetric_difference_iter(&b).collect::<std::vec::Vec<u8>>(),
+            a.symmetric_difference(&b).iter().collect::<std::vec::Vec<u8>>()
+        );
+    }
+}
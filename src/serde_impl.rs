@@ -0,0 +1,94 @@
+use crate::ByteSet;
+use core::fmt;
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+impl Serialize for ByteSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            use serde::ser::SerializeSeq;
+            let mut seq = serializer.serialize_seq(Some(self.len() as usize))?;
+            for val in self.iter() {
+                seq.serialize_element(&val)?;
+            }
+            seq.end()
+        } else {
+            serializer.serialize_bytes(&self.to_le_bytes())
+        }
+    }
+}
+
+struct ByteSetVisitor;
+
+impl<'de> Visitor<'de> for ByteSetVisitor {
+    type Value = ByteSet;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a sequence of u8 values, or a 32-byte little-endian bitmask")
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        let bytes: [u8; 32] = v
+            .try_into()
+            .map_err(|_| E::invalid_length(v.len(), &"32 bytes"))?;
+        Ok(ByteSet::from_le_bytes(bytes))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut set = ByteSet::new();
+        while let Some(val) = seq.next_element::<u8>()? {
+            set.insert(val);
+        }
+        Ok(set)
+    }
+}
+
+impl<'de> Deserialize<'de> for ByteSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_seq(ByteSetVisitor)
+        } else {
+            deserializer.deserialize_bytes(ByteSetVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_test::{assert_de_tokens_error, assert_tokens, Compact, Configure, Token};
+
+    #[test]
+    fn round_trip_human_readable() {
+        let set: ByteSet = [1u8, 5, 255].into_iter().collect();
+        assert_tokens(
+            &set.readable(),
+            &[
+                Token::Seq { len: Some(3) },
+                Token::U8(1),
+                Token::U8(5),
+                Token::U8(255),
+                Token::SeqEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn round_trip_binary() {
+        let set: ByteSet = [0u8, 255].into_iter().collect();
+        let mut bytes = [0u8; 32];
+        bytes[0] = 0b0000_0001;
+        bytes[31] = 0b1000_0000;
+        assert_tokens(&set.compact(), &[Token::Bytes(&bytes)]);
+    }
+
+    #[test]
+    fn rejects_wrong_length_bytes() {
+        assert_de_tokens_error::<Compact<ByteSet>>(
+            &[Token::Bytes(&[0u8; 31])],
+            "invalid length 31, expected 32 bytes",
+        );
+    }
+}